@@ -0,0 +1,77 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    types::{
+        CliCommand, CliTypedResult, FaucetOptions, ProfileOptions, RestOptions, TransactionSummary,
+        TransactionOptions,
+    },
+    utils::fund_account,
+};
+use aptos_types::account_address::AccountAddress;
+use cached_packages::aptos_stdlib;
+use async_trait::async_trait;
+use clap::Parser;
+
+/// Default number of coins to fund a newly created account with
+pub const DEFAULT_FUNDED_COINS: u64 = 100_000_000;
+
+/// Create a new account on-chain
+///
+/// An account can be created by transferring coins, or by using the faucet on a
+/// test network. With `--use-faucet` the new account is funded from the faucet
+/// with [`DEFAULT_FUNDED_COINS`] unless `--initial-coins` overrides it; without
+/// it the account is created on-chain with no coins.
+#[derive(Debug, Parser)]
+pub struct CreateAccount {
+    /// Address of the account to create
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: AccountAddress,
+    /// Use the faucet to fund the new account instead of the caller's balance
+    #[clap(long)]
+    pub(crate) use_faucet: bool,
+    #[clap(flatten)]
+    pub(crate) faucet_options: FaucetOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    /// Coins to fund the new account with when using the faucet
+    #[clap(long, default_value_t = DEFAULT_FUNDED_COINS)]
+    pub(crate) initial_coins: u64,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<TransactionSummary> for CreateAccount {
+    fn command_name(&self) -> &'static str {
+        "CreateAccount"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+        if self.use_faucet {
+            let faucet_client = self.faucet_options.client()?;
+            fund_account(
+                &faucet_client,
+                self.faucet_options
+                    .faucet_url(&self.profile_options.profile)?,
+                self.initial_coins,
+                self.account,
+            )
+            .await?;
+            Ok(TransactionSummary {
+                transaction_hash: "faucet".to_string(),
+                gas_used: None,
+                success: Some(true),
+                version: None,
+                vm_status: None,
+            })
+        } else {
+            self.txn_options
+                .submit_transaction(aptos_stdlib::aptos_account_create_account(self.account))
+                .await
+                .map(TransactionSummary::from)
+        }
+    }
+}
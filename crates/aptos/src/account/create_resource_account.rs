@@ -0,0 +1,149 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    dry_run::{ensure_executed, simulate, DryRunOptions, SimulationSummary},
+    types::{
+        CliCommand, CliConfig, CliError, CliTypedResult, ConfigSearchMode, EncodingOptions,
+        ProfileConfig, TransactionOptions, TransactionSummary,
+    },
+    utils::prompt_yes_with_override,
+    verify::{review_payload, VerifyOptions},
+};
+use aptos_types::account_address::{create_resource_address, AccountAddress};
+use async_trait::async_trait;
+use cached_packages::aptos_stdlib;
+use clap::Parser;
+use rand::{rngs::OsRng, Rng};
+use serde::Serialize;
+
+/// Create a resource account on-chain and register it as a local profile
+///
+/// A resource account is an account owned by another account, with an address
+/// deterministically derived from the owner's address and a seed. This is the
+/// idiomatic way to host Move modules that must be published from a stable,
+/// programmatically-controlled address. The derived address is printed before
+/// submission so it can be verified, and on success a named profile pointing at
+/// the new account is written into `.aptos/config.yaml`.
+#[derive(Debug, Parser)]
+pub struct CreateResourceAccount {
+    /// Name of the profile to create for the new resource account
+    #[clap(long)]
+    pub(crate) resource_account_profile: String,
+    /// Seed used to derive the resource account address
+    ///
+    /// Provided as a hex string (with or without a leading `0x`). When omitted a
+    /// random 32-byte seed is generated.
+    #[clap(long, parse(try_from_str=crate::common::utils::parse_hex_bytes))]
+    pub(crate) seed: Option<Vec<u8>>,
+    #[clap(flatten)]
+    pub(crate) encoding_options: EncodingOptions,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) verify_options: VerifyOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateResourceAccountSummary {
+    pub resource_account: AccountAddress,
+    #[serde(flatten)]
+    pub transaction: TransactionSummary,
+}
+
+/// Either the committed transaction, or the result of a `--dry-run` simulation.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum CreateResourceAccountResult {
+    Submitted(CreateResourceAccountSummary),
+    Simulated(SimulationSummary),
+}
+
+#[async_trait]
+impl CliCommand<CreateResourceAccountResult> for CreateResourceAccount {
+    fn command_name(&self) -> &'static str {
+        "CreateResourceAccount"
+    }
+
+    async fn execute(self) -> CliTypedResult<CreateResourceAccountResult> {
+        let seed = self
+            .seed
+            .unwrap_or_else(|| OsRng.gen::<[u8; 32]>().to_vec());
+        let source = self.txn_options.profile_options.account_address()?;
+        let resource_account = create_resource_address(source, &seed);
+
+        // Surface the derived address before anything is submitted so the user
+        // can confirm it matches their expectation.
+        eprintln!("Resource account address: {}", resource_account);
+
+        let payload = aptos_stdlib::resource_account_create_resource_account(seed, vec![]);
+
+        // Offline review before any signing: decode the payload, print a
+        // semantic summary and the transaction hash, and enforce any
+        // `--expect-*` assertions. Refuses here if the decoded effect differs.
+        let raw_txn = self.txn_options.build_raw_transaction(payload.clone()).await?;
+        let review = review_payload(&raw_txn, &self.verify_options)?;
+        eprintln!(
+            "Transaction review:\n{}",
+            serde_json::to_string_pretty(&review)
+                .map_err(|e| CliError::UnexpectedError(e.to_string()))?
+        );
+
+        // With `--dry-run`, simulate against the node and return without
+        // committing; refuse if the VM status is not `Executed`.
+        if self.dry_run_options.dry_run {
+            let client = self.txn_options.rest_client()?;
+            let summary = simulate(&client, raw_txn, &self.txn_options.public_key()?).await?;
+            ensure_executed(&summary)?;
+            return Ok(CreateResourceAccountResult::Simulated(summary));
+        }
+
+        prompt_yes_with_override(
+            &format!("Create resource account {}?", resource_account),
+            self.txn_options.prompt_options,
+        )?;
+
+        let transaction = self
+            .txn_options
+            .submit_transaction(payload)
+            .await
+            .map(TransactionSummary::from)?;
+
+        // Persist an address-only profile for the resource account. The owner's
+        // private key cannot sign for the resource account (its own auth key is
+        // zeroed and its signer capability is held by the owner), so we only
+        // carry over the network endpoints and record the derived address. This
+        // lets `--profile` target the resource account for reads and as the
+        // `--sender-account` of owner-signed module publishes and scripts.
+        let mut config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        let owner = config
+            .get_profile_config(Some(&self.txn_options.profile_options.profile))?
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(format!(
+                    "Profile {} does not exist",
+                    self.txn_options.profile_options.profile
+                ))
+            })?
+            .clone();
+        let profiles = config.profiles.get_or_insert_with(Default::default);
+        profiles.insert(
+            self.resource_account_profile.clone(),
+            ProfileConfig {
+                account: Some(resource_account),
+                rest_url: owner.rest_url,
+                faucet_url: owner.faucet_url,
+                ..Default::default()
+            },
+        );
+        config.save()?;
+
+        Ok(CreateResourceAccountResult::Submitted(
+            CreateResourceAccountSummary {
+                resource_account,
+                transaction,
+            },
+        ))
+    }
+}
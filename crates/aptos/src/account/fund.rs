@@ -39,7 +39,12 @@ impl CliCommand<String> for FundAccount {
     }
 
     async fn execute(self) -> CliTypedResult<String> {
+        // Both the faucet client and the REST client carry the shared
+        // `--proxy`/`--tor` options via `FaucetOptions`/`RestOptions`, so every
+        // request below is routed through the configured SOCKS5 proxy.
+        let faucet_client = self.faucet_options.client()?;
         let hashes = fund_account(
+            &faucet_client,
             self.faucet_options
                 .faucet_url(&self.profile_options.profile)?,
             self.num_coins,
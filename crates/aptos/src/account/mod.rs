@@ -0,0 +1,37 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliResult};
+use clap::Subcommand;
+
+pub mod create;
+pub mod create_resource_account;
+pub mod fund;
+pub mod multisig;
+pub mod transfer;
+
+/// Tool for interacting with accounts
+///
+/// This tool is used to create accounts, fund accounts, and transfer resources
+/// between accounts.
+#[derive(Debug, Subcommand)]
+pub enum AccountTool {
+    Create(create::CreateAccount),
+    CreateResourceAccount(create_resource_account::CreateResourceAccount),
+    Fund(fund::FundAccount),
+    #[clap(subcommand)]
+    Multisig(multisig::MultisigTool),
+    Transfer(transfer::TransferCoins),
+}
+
+impl AccountTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            AccountTool::Create(tool) => tool.execute_serialized().await,
+            AccountTool::CreateResourceAccount(tool) => tool.execute_serialized().await,
+            AccountTool::Fund(tool) => tool.execute_serialized().await,
+            AccountTool::Multisig(tool) => tool.execute().await,
+            AccountTool::Transfer(tool) => tool.execute_serialized().await,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Multisig account flows.
+//!
+//! A co-signer approving a multisig transaction signs over the inner payload
+//! bytes, so before submitting the approval the CLI decodes those bytes through
+//! [`review_multisig_payload`] and enforces the caller's `--expect-*`
+//! assertions. This stops a signer from approving a payload whose real effect
+//! differs from what was agreed.
+
+use crate::common::{
+    dry_run::{submit_or_dry_run, DryRunOptions, SubmitOrSimulate},
+    types::{CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions},
+    utils::parse_hex_bytes,
+    verify::{review_multisig_payload, VerifyOptions},
+};
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use cached_packages::aptos_stdlib;
+use clap::{Parser, Subcommand};
+
+/// Tool for creating and approving multisig transactions
+#[derive(Debug, Subcommand)]
+pub enum MultisigTool {
+    CreateTransaction(CreateTransaction),
+}
+
+impl MultisigTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            MultisigTool::CreateTransaction(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// Create (and sign) a multisig transaction from a serialized payload
+#[derive(Debug, Parser)]
+pub struct CreateTransaction {
+    /// Address of the multisig account
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) multisig_address: AccountAddress,
+    /// BCS-serialized inner transaction payload, as a hex string
+    #[clap(long, parse(try_from_str = parse_hex_bytes))]
+    pub(crate) payload: Vec<u8>,
+    #[clap(flatten)]
+    pub(crate) verify_options: VerifyOptions,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<SubmitOrSimulate> for CreateTransaction {
+    fn command_name(&self) -> &'static str {
+        "CreateMultisigTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<SubmitOrSimulate> {
+        // Review the inner payload before signing anything.
+        let review = review_multisig_payload(&self.payload, &self.verify_options)?;
+        eprintln!(
+            "Multisig payload review:\n{}",
+            serde_json::to_string_pretty(&review)
+                .map_err(|e| CliError::UnexpectedError(e.to_string()))?
+        );
+
+        let payload = aptos_stdlib::multisig_account_create_transaction(
+            self.multisig_address,
+            self.payload.clone(),
+        );
+        submit_or_dry_run(&self.txn_options, payload, &self.dry_run_options).await
+    }
+}
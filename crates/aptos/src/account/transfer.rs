@@ -0,0 +1,38 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    dry_run::{submit_or_dry_run, DryRunOptions, SubmitOrSimulate},
+    types::{CliCommand, CliTypedResult, TransactionOptions},
+};
+use aptos_types::account_address::AccountAddress;
+use async_trait::async_trait;
+use cached_packages::aptos_stdlib;
+use clap::Parser;
+
+/// Transfer APT between accounts
+#[derive(Debug, Parser)]
+pub struct TransferCoins {
+    /// Address of the recipient
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: AccountAddress,
+    /// Amount of Octas (10^-8 APT) to transfer
+    #[clap(long)]
+    pub(crate) amount: u64,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<SubmitOrSimulate> for TransferCoins {
+    fn command_name(&self) -> &'static str {
+        "TransferCoins"
+    }
+
+    async fn execute(self) -> CliTypedResult<SubmitOrSimulate> {
+        let payload = aptos_stdlib::aptos_account_transfer(self.account, self.amount);
+        submit_or_dry_run(&self.txn_options, payload, &self.dry_run_options).await
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transaction simulation shared by `move_tool`, `governance`, and account
+//! transfers.
+//!
+//! The `--dry-run` flag builds the same signed transaction the real submit path
+//! would, but replaces the signature with a zero/invalid one so the VM skips
+//! authentication, and routes it to the node's simulate endpoint. Nothing is
+//! committed on chain; the caller gets back the VM status, gas estimate, gas
+//! unit price, and a summary of the emitted events and write set. The normal
+//! submit path can run a dry-run first and refuse to submit when the simulated
+//! status is not `Executed`.
+
+use crate::common::types::{
+    CliError, CliTypedResult, TransactionOptions, TransactionSummary,
+};
+use aptos_crypto::{ed25519::Ed25519Signature, PrivateKey};
+use aptos_rest_client::{aptos_api_types::UserTransaction, Client};
+use aptos_types::transaction::{RawTransaction, SignedTransaction, TransactionPayload};
+use clap::Parser;
+use serde::Serialize;
+
+#[derive(Debug, Default, Parser)]
+pub struct DryRunOptions {
+    /// Simulate the transaction against the node instead of committing it
+    ///
+    /// Prints the VM status, estimated gas used, gas unit price, and a summary
+    /// of the emitted events and write set without paying any gas.
+    #[clap(long)]
+    pub dry_run: bool,
+}
+
+/// Human-readable result of a transaction simulation.
+#[derive(Debug, Serialize)]
+pub struct SimulationSummary {
+    pub vm_status: String,
+    pub success: bool,
+    pub gas_used: u64,
+    pub gas_unit_price: u64,
+    pub events: usize,
+    pub changes: usize,
+}
+
+impl From<&UserTransaction> for SimulationSummary {
+    fn from(txn: &UserTransaction) -> Self {
+        SimulationSummary {
+            vm_status: txn.info.vm_status.clone(),
+            success: txn.info.success,
+            gas_used: txn.info.gas_used.0,
+            gas_unit_price: txn.request.gas_unit_price.0,
+            events: txn.events.len(),
+            changes: txn.info.changes.len(),
+        }
+    }
+}
+
+/// Simulate `raw_txn` as signed by `public_key`, returning the VM's view of the
+/// transaction's effects without committing it.
+pub async fn simulate(
+    client: &Client,
+    raw_txn: RawTransaction,
+    public_key: &<aptos_crypto::ed25519::Ed25519PrivateKey as PrivateKey>::PublicKeyMaterial,
+) -> CliTypedResult<SimulationSummary> {
+    // A zero signature is never valid, so the VM only accepts it on the
+    // simulate endpoint, which bypasses signature verification.
+    let signed_txn = SignedTransaction::new(
+        raw_txn,
+        public_key.clone(),
+        Ed25519Signature::dummy_signature(),
+    );
+
+    let txns = client
+        .simulate(&signed_txn)
+        .await
+        .map_err(|err| CliError::ApiError(err.to_string()))?
+        .into_inner();
+
+    let txn = txns
+        .first()
+        .ok_or_else(|| CliError::UnexpectedError("Empty simulation response".to_string()))?;
+
+    Ok(SimulationSummary::from(txn))
+}
+
+/// Refuse to continue when a dry-run's simulated status is not `Executed`.
+pub fn ensure_executed(summary: &SimulationSummary) -> CliTypedResult<()> {
+    if summary.success {
+        Ok(())
+    } else {
+        Err(CliError::SimulationError(summary.vm_status.clone()))
+    }
+}
+
+impl DryRunOptions {
+    /// Simulate `payload` under the sender described by `txn_options`.
+    pub async fn simulate(
+        &self,
+        txn_options: &TransactionOptions,
+        payload: TransactionPayload,
+    ) -> CliTypedResult<SimulationSummary> {
+        let client = txn_options.rest_client()?;
+        let raw_txn = txn_options.build_raw_transaction(payload).await?;
+        simulate(&client, raw_txn, &txn_options.public_key()?).await
+    }
+}
+
+/// Either the committed transaction, or the result of a `--dry-run` simulation.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum SubmitOrSimulate {
+    Committed(TransactionSummary),
+    Simulated(SimulationSummary),
+}
+
+/// Shared submit path for the `move_tool` run commands, governance proposal
+/// execution, and the account transfer and multisig commands.
+///
+/// Always runs a pre-submit dry-run and refuses to commit unless the simulated
+/// status is `Executed`. With `--dry-run` set, returns the simulation without
+/// committing anything on chain.
+pub async fn submit_or_dry_run(
+    txn_options: &TransactionOptions,
+    payload: TransactionPayload,
+    options: &DryRunOptions,
+) -> CliTypedResult<SubmitOrSimulate> {
+    let summary = options.simulate(txn_options, payload.clone()).await?;
+    ensure_executed(&summary)?;
+    if options.dry_run {
+        return Ok(SubmitOrSimulate::Simulated(summary));
+    }
+    let transaction = txn_options
+        .submit_transaction(payload)
+        .await
+        .map(TransactionSummary::from)?;
+    Ok(SubmitOrSimulate::Committed(transaction))
+}
@@ -0,0 +1,9 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod dry_run;
+pub mod init;
+pub mod proxy;
+pub mod types;
+pub mod utils;
+pub mod verify;
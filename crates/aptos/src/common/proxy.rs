@@ -0,0 +1,187 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Optional SOCKS5/Tor proxying for every HTTP client the CLI builds.
+//!
+//! [`ProxyOptions`] is flattened into `RestOptions` and `FaucetOptions` so that
+//! the REST client used by `FundAccount::execute` and the transaction-wait loop,
+//! as well as the faucet client, can be routed through a SOCKS5 proxy. This
+//! enables talking to `.onion` endpoints and privacy-preserving relays. When
+//! `--tor` is passed and a local `tor` binary is present, a throwaway Tor
+//! process is spawned for the lifetime of the command and torn down on exit.
+
+use crate::common::types::{CliError, CliTypedResult};
+use clap::Parser;
+use std::{
+    fs::File,
+    io::Write,
+    net::TcpStream,
+    path::PathBuf,
+    process::{Child, Command},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Fail fast on a dead proxy rather than hanging the transaction-wait loop.
+const PROXY_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to wait for a spawned Tor instance to open its SOCKS port.
+const TOR_BOOTSTRAP_TIMEOUT: Duration = Duration::from_secs(60);
+/// Default SOCKS port used by the auto-spawned Tor instance.
+const DEFAULT_TOR_SOCKS_PORT: u16 = 19050;
+
+#[derive(Debug, Default, Parser)]
+pub struct ProxyOptions {
+    /// SOCKS5 proxy URL to route all REST and faucet traffic through
+    ///
+    /// Example: `socks5h://127.0.0.1:9050`. Use the `h` variant so hostnames
+    /// (including `.onion` addresses) are resolved by the proxy.
+    #[clap(long)]
+    pub proxy: Option<String>,
+    /// Spawn a throwaway local Tor instance and route all traffic through it
+    ///
+    /// Requires a `tor` binary on `PATH`. Mutually exclusive with `--proxy`.
+    #[clap(long)]
+    pub tor: bool,
+}
+
+impl ProxyOptions {
+    /// Apply the configured proxy, if any, to a [`reqwest::ClientBuilder`].
+    ///
+    /// `RestOptions`/`FaucetOptions` call this whenever they build a client, so
+    /// every HTTP client the CLI constructs is routed through the same proxy. A
+    /// spawned Tor instance is handed to a process-lifetime registry so it stays
+    /// up across every client built during the command and is torn down on exit.
+    pub fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> CliTypedResult<reqwest::ClientBuilder> {
+        builder = builder.connect_timeout(PROXY_CONNECT_TIMEOUT);
+
+        if self.tor {
+            if self.proxy.is_some() {
+                return Err(CliError::CommandArgumentError(
+                    "--tor and --proxy are mutually exclusive".to_string(),
+                ));
+            }
+            let socks_url = register_tor()?;
+            let proxy = reqwest::Proxy::all(socks_url)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+            return Ok(builder.proxy(proxy));
+        }
+
+        if let Some(url) = self.proxy.as_deref() {
+            let proxy = reqwest::Proxy::all(normalize_proxy_url(url))
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Process-lifetime registry for the auto-spawned Tor instance.
+///
+/// The first `--tor` client build spawns Tor; subsequent builds reuse it. The
+/// instance is dropped — and its process reaped — when the registry is cleared
+/// via [`shutdown_tor`] at the end of the command.
+static TOR_INSTANCE: Mutex<Option<TorInstance>> = Mutex::new(None);
+
+/// Ensure a Tor instance is running and return its SOCKS URL.
+fn register_tor() -> CliTypedResult<String> {
+    let mut guard = TOR_INSTANCE
+        .lock()
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+    if guard.is_none() {
+        *guard = Some(TorInstance::spawn(DEFAULT_TOR_SOCKS_PORT)?);
+    }
+    Ok(guard.as_ref().expect("just populated").socks_url())
+}
+
+/// Tear down the auto-spawned Tor instance, if any. Called at command exit.
+pub fn shutdown_tor() {
+    if let Ok(mut guard) = TOR_INSTANCE.lock() {
+        guard.take();
+    }
+}
+
+/// Accept onion/proxy host strings with or without scheme decoration, defaulting
+/// bare `host:port` forms to `socks5h://`.
+fn normalize_proxy_url(url: &str) -> String {
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("socks5h://{}", url)
+    }
+}
+
+/// A Tor process owned for the lifetime of a single CLI command.
+///
+/// The SOCKS port is closed and the process is reaped on drop.
+#[derive(Debug)]
+pub struct TorInstance {
+    child: Child,
+    socks_port: u16,
+    _data_dir: PathBuf,
+}
+
+impl TorInstance {
+    fn spawn(socks_port: u16) -> CliTypedResult<Self> {
+        let data_dir = std::env::temp_dir().join(format!("aptos-tor-{}", socks_port));
+        std::fs::create_dir_all(&data_dir)
+            .map_err(|err| CliError::IO(data_dir.display().to_string(), err))?;
+
+        let torrc = data_dir.join("torrc");
+        let mut file = File::create(&torrc)
+            .map_err(|err| CliError::IO(torrc.display().to_string(), err))?;
+        writeln!(
+            file,
+            "SocksPort {}\nDataDirectory {}\nLog notice stderr",
+            socks_port,
+            data_dir.display()
+        )
+        .map_err(|err| CliError::IO(torrc.display().to_string(), err))?;
+
+        let child = Command::new("tor")
+            .arg("-f")
+            .arg(&torrc)
+            .spawn()
+            .map_err(|err| {
+                CliError::UnexpectedError(format!("Failed to spawn tor: {}", err))
+            })?;
+
+        let instance = TorInstance {
+            child,
+            socks_port,
+            _data_dir: data_dir,
+        };
+        instance.wait_for_socks_port()?;
+        Ok(instance)
+    }
+
+    fn socks_url(&self) -> String {
+        format!("socks5h://127.0.0.1:{}", self.socks_port)
+    }
+
+    fn wait_for_socks_port(&self) -> CliTypedResult<()> {
+        let deadline = Instant::now() + TOR_BOOTSTRAP_TIMEOUT;
+        let addr = format!("127.0.0.1:{}", self.socks_port);
+        while Instant::now() < deadline {
+            if TcpStream::connect(&addr).is_ok() {
+                return Ok(());
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+        Err(CliError::UnexpectedError(format!(
+            "Tor SOCKS port {} did not come up within {}s",
+            self.socks_port,
+            TOR_BOOTSTRAP_TIMEOUT.as_secs()
+        )))
+    }
+}
+
+impl Drop for TorInstance {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
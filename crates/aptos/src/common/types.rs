@@ -0,0 +1,487 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{proxy::ProxyOptions, utils::prompt_yes};
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    PrivateKey,
+};
+use aptos_rest_client::{aptos_api_types::Transaction, Client};
+use aptos_types::{
+    account_address::AccountAddress,
+    chain_id::ChainId,
+    transaction::{RawTransaction, TransactionPayload},
+};
+use async_trait::async_trait;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+use thiserror::Error;
+
+/// Default timeout (seconds) used when building REST clients.
+const DEFAULT_CONNECTION_TIMEOUT_SECS: u64 = 30;
+const CONFIG_FOLDER: &str = ".aptos";
+const CONFIG_FILE: &str = "config.yaml";
+
+/// Errors surfaced by the CLI
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("Aborted command")]
+    AbortedError,
+    #[error("Invalid arguments: {0}")]
+    CommandArgumentError(String),
+    #[error("Unable to load config: {0}")]
+    ConfigNotFoundError(String),
+    #[error("Error accessing '{0}': {1}")]
+    IO(String, #[source] std::io::Error),
+    #[error("API error: {0}")]
+    ApiError(String),
+    #[error("Simulation failed with status: {0}")]
+    SimulationError(String),
+    #[error("Unexpected error: {0}")]
+    UnexpectedError(String),
+}
+
+/// The result type the CLI uses internally before serialization.
+pub type CliTypedResult<T> = Result<T, CliError>;
+/// The top-level result type returned to the binary: `Ok`/`Err` of a rendered
+/// string so every command serializes the same way.
+pub type CliResult = Result<String, String>;
+
+/// How far up the directory tree to search for the `.aptos` config folder.
+#[derive(Debug, Clone, Copy)]
+pub enum ConfigSearchMode {
+    CurrentDir,
+    CurrentDirAndParents,
+}
+
+/// Trait every CLI command implements; the blanket `execute_serialized*`
+/// helpers turn a typed result into the rendered [`CliResult`].
+#[async_trait]
+pub trait CliCommand<T: Serialize + Send>: Sized + Send {
+    /// Name reported in telemetry and error messages
+    fn command_name(&self) -> &'static str;
+
+    /// Run the command, producing a typed result
+    async fn execute(self) -> CliTypedResult<T>;
+
+    /// Run the command and render the result as pretty JSON
+    async fn execute_serialized(self) -> CliResult {
+        let command_name = self.command_name();
+        to_common_result(command_name, self.execute().await)
+    }
+
+    /// Run the command, discarding the value and rendering a success marker
+    async fn execute_serialized_success(self) -> CliResult {
+        let command_name = self.command_name();
+        to_common_result(command_name, self.execute().await.map(|_| "Success"))
+    }
+}
+
+fn to_common_result<T: Serialize>(command_name: &str, result: CliTypedResult<T>) -> CliResult {
+    match result {
+        Ok(inner) => Ok(serde_json::to_string_pretty(&inner)
+            .unwrap_or_else(|err| format!("{} succeeded but failed to render: {}", command_name, err))),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Prompt-suppression flag shared by commands that confirm before acting
+#[derive(Debug, Default, Parser)]
+pub struct PromptOptions {
+    /// Assume yes for all prompts
+    #[clap(long)]
+    pub assume_yes: bool,
+    /// Assume no for all prompts
+    #[clap(long)]
+    pub assume_no: bool,
+}
+
+/// Input/output encoding for keys and payloads
+#[derive(Debug, Default, Parser)]
+pub struct EncodingOptions {
+    /// Encoding of data as one of [base64, bcs, hex]
+    #[clap(long, default_value = "hex")]
+    pub encoding: String,
+}
+
+/// Selects which profile in `.aptos/config.yaml` a command operates against
+#[derive(Debug, Parser)]
+pub struct ProfileOptions {
+    /// Profile to use from the CLI config
+    #[clap(long, default_value = "default")]
+    pub profile: String,
+}
+
+impl Default for ProfileOptions {
+    fn default() -> Self {
+        ProfileOptions {
+            profile: "default".to_string(),
+        }
+    }
+}
+
+impl ProfileOptions {
+    /// Resolve the account address recorded for the selected profile
+    pub fn account_address(&self) -> CliTypedResult<AccountAddress> {
+        let config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        config
+            .get_profile_config(Some(&self.profile))?
+            .and_then(|profile| profile.account)
+            .ok_or_else(|| {
+                CliError::ConfigNotFoundError(format!(
+                    "No account found for profile {}",
+                    self.profile
+                ))
+            })
+    }
+}
+
+/// Options describing how to reach the node's REST endpoint
+#[derive(Debug, Default, Parser)]
+pub struct RestOptions {
+    /// URL to a fullnode's REST API endpoint
+    #[clap(long)]
+    pub url: Option<reqwest::Url>,
+    /// Connection timeout in seconds, fails fast if the node is unreachable
+    #[clap(long, default_value_t = DEFAULT_CONNECTION_TIMEOUT_SECS)]
+    pub connection_timeout_secs: u64,
+    #[clap(flatten)]
+    pub proxy_options: ProxyOptions,
+}
+
+impl RestOptions {
+    pub fn new(url: Option<reqwest::Url>, connection_timeout_secs: Option<u64>) -> Self {
+        RestOptions {
+            url,
+            connection_timeout_secs: connection_timeout_secs
+                .unwrap_or(DEFAULT_CONNECTION_TIMEOUT_SECS),
+            proxy_options: ProxyOptions::default(),
+        }
+    }
+
+    /// Resolve the REST URL, falling back to the profile's recorded endpoint
+    pub fn url(&self, profile: &str) -> CliTypedResult<reqwest::Url> {
+        if let Some(url) = self.url.as_ref() {
+            return Ok(url.clone());
+        }
+        let config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        config
+            .get_profile_config(Some(profile))?
+            .and_then(|profile| profile.rest_url)
+            .map(|url| reqwest::Url::parse(&url))
+            .transpose()
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(
+                    "No rest url given and no profile found".to_string(),
+                )
+            })
+    }
+
+    /// Build a REST client for `profile`, routing through the configured proxy.
+    pub fn client(&self, profile: &str) -> CliTypedResult<Client> {
+        let url = self.url(profile)?;
+        let builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(self.connection_timeout_secs));
+        let builder = self.proxy_options.apply(builder)?;
+        let inner = builder
+            .build()
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        Ok(Client::from((inner, url)))
+    }
+}
+
+/// Options describing how to reach a faucet
+#[derive(Debug, Default, Parser)]
+pub struct FaucetOptions {
+    /// URL for the faucet endpoint
+    #[clap(long)]
+    pub faucet_url: Option<reqwest::Url>,
+    #[clap(flatten)]
+    pub proxy_options: ProxyOptions,
+}
+
+impl FaucetOptions {
+    pub fn new(faucet_url: Option<reqwest::Url>) -> Self {
+        FaucetOptions {
+            faucet_url,
+            proxy_options: ProxyOptions::default(),
+        }
+    }
+
+    /// Resolve the faucet URL, falling back to the profile's recorded endpoint
+    pub fn faucet_url(&self, profile: &str) -> CliTypedResult<reqwest::Url> {
+        if let Some(url) = self.faucet_url.as_ref() {
+            return Ok(url.clone());
+        }
+        let config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        config
+            .get_profile_config(Some(profile))?
+            .and_then(|profile| profile.faucet_url)
+            .map(|url| reqwest::Url::parse(&url))
+            .transpose()
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(
+                    "No faucet url given and no profile found".to_string(),
+                )
+            })
+    }
+
+    /// Build a faucet HTTP client routed through the configured proxy.
+    pub fn client(&self) -> CliTypedResult<reqwest::Client> {
+        let builder = self.proxy_options.apply(reqwest::Client::builder())?;
+        builder
+            .build()
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))
+    }
+}
+
+/// Options shared by all transaction-submitting commands
+#[derive(Debug, Parser)]
+pub struct TransactionOptions {
+    #[clap(flatten)]
+    pub profile_options: ProfileOptions,
+    #[clap(flatten)]
+    pub rest_options: RestOptions,
+    #[clap(flatten)]
+    pub prompt_options: PromptOptions,
+    /// Maximum gas units willing to be spent
+    #[clap(long, default_value_t = 100_000)]
+    pub max_gas: u64,
+    /// Gas unit price in Octas
+    #[clap(long, default_value_t = 100)]
+    pub gas_unit_price: u64,
+}
+
+impl TransactionOptions {
+    /// REST client for the selected profile
+    pub fn rest_client(&self) -> CliTypedResult<Client> {
+        self.rest_options.client(&self.profile_options.profile)
+    }
+
+    /// Public key of the sender derived from the selected profile
+    pub fn public_key(&self) -> CliTypedResult<Ed25519PublicKey> {
+        let config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        config
+            .get_profile_config(Some(&self.profile_options.profile))?
+            .and_then(|profile| profile.private_key)
+            .map(|key| key.public_key())
+            .ok_or_else(|| {
+                CliError::ConfigNotFoundError(format!(
+                    "No private key found for profile {}",
+                    self.profile_options.profile
+                ))
+            })
+    }
+
+    /// Build an unsigned transaction for `payload` using on-chain sequence data.
+    ///
+    /// This is the shared entry point used both by the real submit path and by
+    /// `--dry-run` simulation. Each call builds a fresh transaction with the
+    /// current sequence number and an expiration 30 seconds from now, so two
+    /// calls for the same payload can produce different bytes.
+    pub async fn build_raw_transaction(
+        &self,
+        payload: TransactionPayload,
+    ) -> CliTypedResult<RawTransaction> {
+        let client = self.rest_client()?;
+        let sender = self.profile_options.account_address()?;
+        let account = client
+            .get_account(sender)
+            .await
+            .map_err(|err| CliError::ApiError(err.to_string()))?
+            .into_inner();
+        let state = client
+            .get_ledger_information()
+            .await
+            .map_err(|err| CliError::ApiError(err.to_string()))?
+            .into_inner();
+        let expiration = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+            .as_secs()
+            + 30;
+        Ok(RawTransaction::new(
+            sender,
+            account.sequence_number,
+            payload,
+            self.max_gas,
+            self.gas_unit_price,
+            expiration,
+            ChainId::new(state.chain_id),
+        ))
+    }
+
+    /// Sign and submit `payload`, returning the committed transaction.
+    pub async fn submit_transaction(
+        &self,
+        payload: TransactionPayload,
+    ) -> CliTypedResult<Transaction> {
+        let client = self.rest_client()?;
+        let raw_txn = self.build_raw_transaction(payload).await?;
+        let config = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)?;
+        let private_key = config
+            .get_profile_config(Some(&self.profile_options.profile))?
+            .and_then(|profile| profile.private_key)
+            .ok_or_else(|| {
+                CliError::ConfigNotFoundError(format!(
+                    "No private key found for profile {}",
+                    self.profile_options.profile
+                ))
+            })?;
+        let signed_txn = raw_txn
+            .sign(&private_key, private_key.public_key())
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+            .into_inner();
+        Ok(client
+            .submit_and_wait(&signed_txn)
+            .await
+            .map_err(|err| CliError::ApiError(err.to_string()))?
+            .into_inner())
+    }
+}
+
+/// Condensed view of a committed transaction returned by submit commands
+#[derive(Debug, Serialize)]
+pub struct TransactionSummary {
+    pub transaction_hash: String,
+    pub gas_used: Option<u64>,
+    pub success: Option<bool>,
+    pub version: Option<u64>,
+    pub vm_status: Option<String>,
+}
+
+impl From<Transaction> for TransactionSummary {
+    fn from(transaction: Transaction) -> Self {
+        match transaction {
+            Transaction::UserTransaction(txn) => TransactionSummary {
+                transaction_hash: txn.info.hash.to_string(),
+                gas_used: Some(txn.info.gas_used.0),
+                success: Some(txn.info.success),
+                version: Some(txn.info.version.0),
+                vm_status: Some(txn.info.vm_status),
+            },
+            _ => TransactionSummary {
+                transaction_hash: transaction.transaction_info().unwrap().hash.to_string(),
+                gas_used: None,
+                success: None,
+                version: None,
+                vm_status: None,
+            },
+        }
+    }
+}
+
+/// On-disk CLI configuration (`.aptos/config.yaml`)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CliConfig {
+    pub profiles: Option<BTreeMap<String, ProfileConfig>>,
+}
+
+/// A single named profile
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ProfileConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<Ed25519PrivateKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<Ed25519PublicKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rest_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faucet_url: Option<String>,
+}
+
+impl CliConfig {
+    fn config_dir(mode: ConfigSearchMode) -> CliTypedResult<PathBuf> {
+        let current = std::env::current_dir()
+            .map_err(|err| CliError::IO("current dir".to_string(), err))?;
+        match mode {
+            ConfigSearchMode::CurrentDir => Ok(current.join(CONFIG_FOLDER)),
+            ConfigSearchMode::CurrentDirAndParents => {
+                let mut dir = current.as_path();
+                loop {
+                    let candidate = dir.join(CONFIG_FOLDER);
+                    if candidate.is_dir() {
+                        return Ok(candidate);
+                    }
+                    match dir.parent() {
+                        Some(parent) => dir = parent,
+                        None => return Ok(current.join(CONFIG_FOLDER)),
+                    }
+                }
+            },
+        }
+    }
+
+    /// Load the config, returning an empty config when none exists yet
+    pub fn load(mode: ConfigSearchMode) -> CliTypedResult<Self> {
+        let path = Self::config_dir(mode)?.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(CliConfig::default());
+        }
+        let bytes = std::fs::read(&path)
+            .map_err(|err| CliError::IO(path.display().to_string(), err))?;
+        serde_yaml::from_slice(&bytes)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))
+    }
+
+    /// Persist the config to `.aptos/config.yaml` in the current directory
+    pub fn save(&self) -> CliTypedResult<()> {
+        let dir = Self::config_dir(ConfigSearchMode::CurrentDir)?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| CliError::IO(dir.display().to_string(), err))?;
+        let path = dir.join(CONFIG_FILE);
+        let bytes = serde_yaml::to_vec(self)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        std::fs::write(&path, bytes)
+            .map_err(|err| CliError::IO(path.display().to_string(), err))
+    }
+
+    /// Fetch a profile's config by name (defaulting to `default`)
+    pub fn get_profile_config(
+        &self,
+        profile: Option<&str>,
+    ) -> CliTypedResult<Option<&ProfileConfig>> {
+        let profile = profile.unwrap_or("default");
+        Ok(self.profiles.as_ref().and_then(|map| map.get(profile)))
+    }
+}
+
+/// Parse an account argument, accepting either a hex address or a profile name
+pub fn load_account_arg(str: &str) -> Result<AccountAddress, CliError> {
+    if let Ok(address) = AccountAddress::from_hex_literal(str) {
+        Ok(address)
+    } else if let Some(Some(account)) = CliConfig::load(ConfigSearchMode::CurrentDirAndParents)
+        .ok()
+        .and_then(|config| config.get_profile_config(Some(str)).ok())
+        .map(|profile| profile.and_then(|profile| profile.account))
+    {
+        Ok(account)
+    } else {
+        Err(CliError::CommandArgumentError(format!(
+            "Failed to parse account from '{}'",
+            str
+        )))
+    }
+}
+
+/// Confirm an action unless a [`PromptOptions`] override is set
+pub fn prompt_confirm(message: &str, options: PromptOptions) -> CliTypedResult<()> {
+    if options.assume_yes {
+        Ok(())
+    } else if options.assume_no {
+        Err(CliError::AbortedError)
+    } else if prompt_yes(message) {
+        Ok(())
+    } else {
+        Err(CliError::AbortedError)
+    }
+}
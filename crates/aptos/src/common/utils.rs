@@ -0,0 +1,66 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliError, CliTypedResult, PromptOptions};
+use aptos_crypto::HashValue;
+use aptos_types::account_address::AccountAddress;
+use std::io::{self, Write};
+
+/// Mint `num_coins` to `address` from the faucet at `faucet_url`, using the
+/// supplied (optionally proxied) HTTP client, and return the resulting
+/// transaction hashes so the caller can wait for them.
+pub async fn fund_account(
+    client: &reqwest::Client,
+    faucet_url: reqwest::Url,
+    num_coins: u64,
+    address: AccountAddress,
+) -> CliTypedResult<Vec<HashValue>> {
+    let response = client
+        .post(format!(
+            "{}mint?amount={}&auth_key={}",
+            faucet_url, num_coins, address
+        ))
+        .send()
+        .await
+        .map_err(|err| CliError::ApiError(err.to_string()))?;
+    if !response.status().is_success() {
+        return Err(CliError::ApiError(format!(
+            "Faucet request failed with status {}",
+            response.status()
+        )));
+    }
+    response
+        .json()
+        .await
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))
+}
+
+/// Parse a hex string (with or without a leading `0x`) into raw bytes.
+pub fn parse_hex_bytes(str: &str) -> CliTypedResult<Vec<u8>> {
+    hex::decode(str.strip_prefix("0x").unwrap_or(str))
+        .map_err(|err| CliError::CommandArgumentError(err.to_string()))
+}
+
+/// Prompt the user with a yes/no question, defaulting to no on EOF.
+pub fn prompt_yes(prompt: &str) -> bool {
+    print!("{} [yes/no] > ", prompt);
+    let _ = io::stdout().flush();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    matches!(input.trim().to_lowercase().as_str(), "yes" | "y")
+}
+
+/// Prompt unless a [`PromptOptions`] override answers for the user.
+pub fn prompt_yes_with_override(prompt: &str, options: PromptOptions) -> CliTypedResult<()> {
+    if options.assume_yes {
+        Ok(())
+    } else if options.assume_no {
+        Err(CliError::AbortedError)
+    } else if prompt_yes(prompt) {
+        Ok(())
+    } else {
+        Err(CliError::AbortedError)
+    }
+}
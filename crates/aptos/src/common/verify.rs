@@ -0,0 +1,185 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Offline review of a serialized transaction payload before signing.
+//!
+//! Governance proposal execution and any multi-party (multisig) flow can route
+//! a BCS-encoded payload through [`review_payload`] first. It decodes the bytes,
+//! prints a semantic summary (target function, type args, recipient addresses,
+//! and amounts), and asserts the caller's expectations. The CLI refuses to sign
+//! or submit when the decoded payload does not pay the expected amount to the
+//! expected address, closing the class of bugs where a signer approves bytes
+//! whose actual effect differs from their intent. The computed hash of the
+//! reviewed bytes is printed so independent reviewers can compare them.
+
+use crate::common::types::{CliError, CliTypedResult};
+use aptos_crypto::HashValue;
+use aptos_types::{
+    account_address::AccountAddress,
+    transaction::{RawTransaction, TransactionPayload},
+};
+use clap::Parser;
+use serde::Serialize;
+
+/// Entry functions whose first two arguments are `(recipient, amount)`.
+///
+/// Positional decoding of args as an address + amount is only meaningful for
+/// these known transfer functions; applying it to arbitrary entry functions
+/// would mislabel unrelated arguments.
+const TRANSFER_FUNCTIONS: &[&str] = &[
+    "0x1::coin::transfer",
+    "0x1::aptos_account::transfer",
+    "0x1::aptos_account::transfer_coins",
+];
+
+#[derive(Debug, Default, Parser)]
+pub struct VerifyOptions {
+    /// Address the payload is expected to pay
+    ///
+    /// Signing is refused if the decoded payload transfers to any other address.
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub expect_recipient: Option<AccountAddress>,
+    /// Amount the payload is expected to transfer to `--expect-recipient`
+    #[clap(long)]
+    pub expect_amount: Option<u64>,
+}
+
+/// Human-readable, semantic summary of a decoded payload.
+#[derive(Debug, Serialize)]
+pub struct PayloadReview {
+    pub function: String,
+    pub type_args: Vec<String>,
+    pub recipient: Option<AccountAddress>,
+    pub amount: Option<u64>,
+    pub transaction_hash: HashValue,
+}
+
+/// Decode `raw_txn`, summarize its effect, and enforce `options`' expectations.
+///
+/// Used by the governance execute path before it signs. The hash is over the
+/// signing message of `raw_txn` (see [`PayloadReview::transaction_hash`]).
+pub fn review_payload(
+    raw_txn: &RawTransaction,
+    options: &VerifyOptions,
+) -> CliTypedResult<PayloadReview> {
+    let (function, type_args, recipient, amount) = inspect_payload(raw_txn.payload())?;
+    enforce_expectations(&function, recipient, amount, options)?;
+
+    // Hash the signing message of `raw_txn`: the BCS of the raw transaction
+    // under the `RawTransaction` signing prefix. Commands that submit after the
+    // review build and sign a fresh raw transaction, whose expiration (and so
+    // whose hash) can differ from the one reviewed here.
+    let signing_message = raw_txn
+        .signing_message()
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+    let transaction_hash = HashValue::sha3_256_of(&signing_message);
+
+    Ok(PayloadReview {
+        function,
+        type_args,
+        recipient,
+        amount,
+        transaction_hash,
+    })
+}
+
+/// Decode a BCS-serialized multisig payload, summarize it, and enforce
+/// expectations before a co-signer approves it.
+///
+/// Multisig approvals sign over the payload bytes rather than a full raw
+/// transaction, so the hash is computed directly over `payload_bytes` — the
+/// same bytes every co-signer must agree on.
+pub fn review_multisig_payload(
+    payload_bytes: &[u8],
+    options: &VerifyOptions,
+) -> CliTypedResult<PayloadReview> {
+    let payload = bcs::from_bytes::<TransactionPayload>(payload_bytes)
+        .map_err(|err| CliError::CommandArgumentError(err.to_string()))?;
+    let (function, type_args, recipient, amount) = inspect_payload(&payload)?;
+    enforce_expectations(&function, recipient, amount, options)?;
+
+    let transaction_hash = HashValue::sha3_256_of(payload_bytes);
+    Ok(PayloadReview {
+        function,
+        type_args,
+        recipient,
+        amount,
+        transaction_hash,
+    })
+}
+
+/// Extract the semantic fields from an entry-function payload.
+fn inspect_payload(
+    payload: &TransactionPayload,
+) -> CliTypedResult<(String, Vec<String>, Option<AccountAddress>, Option<u64>)> {
+    match payload {
+        TransactionPayload::EntryFunction(entry) => {
+            let function = format!(
+                "{}::{}",
+                entry.module().short_str_lossless(),
+                entry.function()
+            );
+            let type_args = entry
+                .ty_args()
+                .iter()
+                .map(|ty| ty.to_string())
+                .collect::<Vec<_>>();
+            // Only decode args as a `(recipient, amount)` pair for known transfer
+            // functions; for anything else the positions carry unrelated data.
+            let (recipient, amount) = if TRANSFER_FUNCTIONS.contains(&function.as_str()) {
+                let recipient = entry
+                    .args()
+                    .first()
+                    .and_then(|arg| bcs::from_bytes::<AccountAddress>(arg).ok());
+                let amount = entry
+                    .args()
+                    .get(1)
+                    .and_then(|arg| bcs::from_bytes::<u64>(arg).ok());
+                (recipient, amount)
+            } else {
+                (None, None)
+            };
+            Ok((function, type_args, recipient, amount))
+        },
+        other => Err(CliError::CommandArgumentError(format!(
+            "Cannot review payload of type {:?}",
+            std::mem::discriminant(other)
+        ))),
+    }
+}
+
+/// Refuse to proceed unless the decoded payload matches the caller's `--expect-*`.
+fn enforce_expectations(
+    function: &str,
+    recipient: Option<AccountAddress>,
+    amount: Option<u64>,
+    options: &VerifyOptions,
+) -> CliTypedResult<()> {
+    // Expectations can only be honored against a recognized transfer; refusing
+    // here is what closes the "approved bytes differ from intent" gap, rather
+    // than silently passing when the payload isn't a transfer at all.
+    let is_transfer = TRANSFER_FUNCTIONS.contains(&function);
+    if (options.expect_recipient.is_some() || options.expect_amount.is_some()) && !is_transfer {
+        return Err(CliError::CommandArgumentError(format!(
+            "Cannot verify recipient/amount: {} is not a recognized transfer function",
+            function
+        )));
+    }
+    if let Some(expected) = options.expect_recipient {
+        if recipient != Some(expected) {
+            return Err(CliError::CommandArgumentError(format!(
+                "Payload recipient {:?} does not match expected {}",
+                recipient, expected
+            )));
+        }
+    }
+    if let Some(expected) = options.expect_amount {
+        if amount != Some(expected) {
+            return Err(CliError::CommandArgumentError(format!(
+                "Payload amount {:?} does not match expected {}",
+                amount, expected
+            )));
+        }
+    }
+    Ok(())
+}
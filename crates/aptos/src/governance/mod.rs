@@ -0,0 +1,71 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! On-chain governance: creating, voting on, and executing proposals.
+//!
+//! Proposal execution submits a transaction like any other command, so it
+//! shares the `--dry-run` simulation path via [`submit_or_dry_run`].
+
+use crate::common::{
+    dry_run::{submit_or_dry_run, DryRunOptions, SubmitOrSimulate},
+    types::{CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions},
+    verify::{review_payload, VerifyOptions},
+};
+use async_trait::async_trait;
+use cached_packages::aptos_stdlib;
+use clap::{Parser, Subcommand};
+
+/// Tool for interacting with on-chain governance
+#[derive(Debug, Subcommand)]
+pub enum GovernanceTool {
+    ExecuteProposal(ExecuteProposal),
+}
+
+impl GovernanceTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            GovernanceTool::ExecuteProposal(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// Execute an approved governance proposal
+#[derive(Debug, Parser)]
+pub struct ExecuteProposal {
+    /// Id of the proposal to execute
+    #[clap(long)]
+    pub(crate) proposal_id: u64,
+    #[clap(flatten)]
+    pub(crate) verify_options: VerifyOptions,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<SubmitOrSimulate> for ExecuteProposal {
+    fn command_name(&self) -> &'static str {
+        "ExecuteProposal"
+    }
+
+    async fn execute(self) -> CliTypedResult<SubmitOrSimulate> {
+        let payload = aptos_stdlib::aptos_governance_resolve(self.proposal_id);
+
+        // Offline review before signing: decode the payload, print a semantic
+        // summary plus the reviewed transaction's hash, and enforce `--expect-*`. Refuses
+        // here if the decoded effect differs from what the signer intends.
+        let raw_txn = self
+            .txn_options
+            .build_raw_transaction(payload.clone())
+            .await?;
+        let review = review_payload(&raw_txn, &self.verify_options)?;
+        eprintln!(
+            "Transaction review:\n{}",
+            serde_json::to_string_pretty(&review)
+                .map_err(|e| CliError::UnexpectedError(e.to_string()))?
+        );
+
+        submit_or_dry_run(&self.txn_options, payload, &self.dry_run_options).await
+    }
+}
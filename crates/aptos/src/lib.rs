@@ -14,7 +14,7 @@ pub mod op;
 #[cfg(any(test, feature = "fuzzing"))]
 pub mod test;
 
-use crate::common::types::{CliCommand, CliResult, CliTypedResult};
+use crate::common::types::{CliCommand, CliResult, CliTypedResult, ProfileOptions, RestOptions};
 use aptos_telemetry::collect_build_information;
 use async_trait::async_trait;
 use clap::Parser;
@@ -32,6 +32,7 @@ pub enum Tool {
     Genesis(genesis::GenesisTool),
     #[clap(subcommand)]
     Governance(governance::GovernanceTool),
+    ChainInfo(ChainInfoTool),
     Info(InfoTool),
     Init(common::init::InitTool),
     #[clap(subcommand)]
@@ -45,18 +46,22 @@ pub enum Tool {
 impl Tool {
     pub async fn execute(self) -> CliResult {
         use Tool::*;
-        match self {
+        let result = match self {
             Account(tool) => tool.execute().await,
             Config(tool) => tool.execute().await,
             Genesis(tool) => tool.execute().await,
             Governance(tool) => tool.execute().await,
+            ChainInfo(tool) => tool.execute_serialized().await,
             Info(tool) => tool.execute_serialized().await,
             // TODO: Replace entirely with config init
             Init(tool) => tool.execute_serialized_success().await,
             Key(tool) => tool.execute().await,
             Move(tool) => tool.execute().await,
             Node(tool) => tool.execute().await,
-        }
+        };
+        // Tear down any Tor instance spawned by `--tor` for this command.
+        crate::common::proxy::shutdown_tor();
+        result
     }
 }
 
@@ -78,3 +83,50 @@ impl CliCommand<BTreeMap<String, String>> for InfoTool {
         Ok(build_information)
     }
 }
+
+/// Show live ledger metadata for the node a profile points at
+///
+/// Unlike `Info`, which reports static build information about the binary, this
+/// queries the node's ledger info endpoint so users and scripts can confirm
+/// which network a profile actually targets and how far behind the node is.
+#[derive(Parser)]
+pub struct ChainInfoTool {
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<BTreeMap<String, String>> for ChainInfoTool {
+    fn command_name(&self) -> &'static str {
+        "GetChainInfo"
+    }
+
+    async fn execute(self) -> CliTypedResult<BTreeMap<String, String>> {
+        let client = self.rest_options.client(&self.profile_options.profile)?;
+        let state = client.get_ledger_information().await?.into_inner();
+        // `node_role` lives on the index response, not the ledger-info `State`.
+        let index = client.get_index().await?.into_inner();
+
+        let mut info = BTreeMap::new();
+        info.insert("chain_id".to_string(), state.chain_id.to_string());
+        info.insert("epoch".to_string(), state.epoch.to_string());
+        info.insert("ledger_version".to_string(), state.version.to_string());
+        info.insert(
+            "ledger_timestamp".to_string(),
+            state.timestamp_usecs.to_string(),
+        );
+        info.insert("block_height".to_string(), state.block_height.to_string());
+        info.insert(
+            "oldest_ledger_version".to_string(),
+            state.oldest_ledger_version.to_string(),
+        );
+        info.insert(
+            "oldest_block_height".to_string(),
+            state.oldest_block_height.to_string(),
+        );
+        info.insert("node_role".to_string(), format!("{:?}", index.node_role));
+        Ok(info)
+    }
+}
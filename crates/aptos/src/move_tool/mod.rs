@@ -0,0 +1,139 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tooling for compiling, publishing, and running Move code.
+//!
+//! The execution subcommands ([`RunFunction`] and [`RunScript`]) share the
+//! `--dry-run` simulation path with the rest of the CLI via
+//! [`submit_or_dry_run`], so users can estimate gas and catch aborts before
+//! paying for on-chain execution.
+
+use crate::common::{
+    dry_run::{submit_or_dry_run, DryRunOptions, SubmitOrSimulate},
+    types::{CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions},
+    utils::parse_hex_bytes,
+};
+use aptos_types::transaction::{EntryFunction, Script, TransactionArgument, TransactionPayload};
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use std::{fs, path::PathBuf, str::FromStr};
+
+/// Tool for compiling, publishing, and running Move
+#[derive(Debug, Subcommand)]
+pub enum MoveTool {
+    RunFunction(RunFunction),
+    RunScript(RunScript),
+}
+
+impl MoveTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            MoveTool::RunFunction(tool) => tool.execute_serialized().await,
+            MoveTool::RunScript(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// Parse a fully-qualified entry function id (`address::module::function`).
+fn parse_function_id(function_id: &str) -> CliTypedResult<(ModuleId, String)> {
+    let parts: Vec<_> = function_id.split("::").collect();
+    if parts.len() != 3 {
+        return Err(CliError::CommandArgumentError(format!(
+            "Function id '{}' must be of the form address::module::function",
+            function_id
+        )));
+    }
+    let module = ModuleId::from_str(&format!("{}::{}", parts[0], parts[1]))
+        .map_err(|err| CliError::CommandArgumentError(err.to_string()))?;
+    Ok((module, parts[2].to_string()))
+}
+
+/// Run an entry function on-chain
+#[derive(Debug, Parser)]
+pub struct RunFunction {
+    /// Fully-qualified function id, e.g. `0x1::aptos_account::transfer`
+    #[clap(long)]
+    pub(crate) function_id: String,
+    /// Type arguments for the function
+    #[clap(long, multiple_values = true)]
+    pub(crate) type_args: Vec<String>,
+    /// BCS-encoded arguments as hex strings
+    #[clap(long, multiple_values = true, parse(try_from_str = parse_hex_bytes))]
+    pub(crate) args: Vec<Vec<u8>>,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<SubmitOrSimulate> for RunFunction {
+    fn command_name(&self) -> &'static str {
+        "RunFunction"
+    }
+
+    async fn execute(self) -> CliTypedResult<SubmitOrSimulate> {
+        let (module, function) = parse_function_id(&self.function_id)?;
+        let type_args = self
+            .type_args
+            .iter()
+            .map(|ty| {
+                TypeTag::from_str(ty).map_err(|err| CliError::CommandArgumentError(err.to_string()))
+            })
+            .collect::<CliTypedResult<Vec<_>>>()?;
+        let payload = TransactionPayload::EntryFunction(EntryFunction::new(
+            module,
+            ident_from_str(&function)?,
+            type_args,
+            self.args,
+        ));
+        submit_or_dry_run(&self.txn_options, payload, &self.dry_run_options).await
+    }
+}
+
+/// Run a compiled Move script on-chain
+#[derive(Debug, Parser)]
+pub struct RunScript {
+    /// Path to the compiled script bytecode
+    #[clap(long)]
+    pub(crate) compiled_script_path: PathBuf,
+    /// Type arguments for the script
+    #[clap(long, multiple_values = true)]
+    pub(crate) type_args: Vec<String>,
+    #[clap(flatten)]
+    pub(crate) dry_run_options: DryRunOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<SubmitOrSimulate> for RunScript {
+    fn command_name(&self) -> &'static str {
+        "RunScript"
+    }
+
+    async fn execute(self) -> CliTypedResult<SubmitOrSimulate> {
+        let code = fs::read(&self.compiled_script_path).map_err(|err| {
+            CliError::IO(self.compiled_script_path.display().to_string(), err)
+        })?;
+        let type_args = self
+            .type_args
+            .iter()
+            .map(|ty| {
+                TypeTag::from_str(ty).map_err(|err| CliError::CommandArgumentError(err.to_string()))
+            })
+            .collect::<CliTypedResult<Vec<_>>>()?;
+        let payload = TransactionPayload::Script(Script::new(
+            code,
+            type_args,
+            Vec::<TransactionArgument>::new(),
+        ));
+        submit_or_dry_run(&self.txn_options, payload, &self.dry_run_options).await
+    }
+}
+
+fn ident_from_str(name: &str) -> CliTypedResult<move_core_types::identifier::Identifier> {
+    move_core_types::identifier::Identifier::new(name)
+        .map_err(|err| CliError::CommandArgumentError(err.to_string()))
+}
@@ -0,0 +1,112 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! A reusable local-testnet harness for integration tests.
+//!
+//! [`LocalTestnet`] spins up a throwaway node plus faucet backed by a child
+//! process, waits for the ledger-info endpoint to report readiness, and tears
+//! everything down on drop. It hands back [`RestOptions`] and [`FaucetOptions`]
+//! pre-filled for the ephemeral instance so commands such as `FundAccount` can
+//! be exercised end-to-end against a real faucet rather than a mock. It is meant
+//! to be usable both from this crate's tests and from downstream integration
+//! suites.
+
+use crate::common::types::{FaucetOptions, RestOptions};
+use reqwest::Url;
+use std::{
+    process::{Child, Command},
+    time::{Duration, Instant},
+};
+
+/// How long to wait for the local node to start serving ledger info.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(60);
+const DEFAULT_REST_PORT: u16 = 8080;
+const DEFAULT_FAUCET_PORT: u16 = 8081;
+
+/// A throwaway local node and faucet owned for the lifetime of a test.
+///
+/// Node and faucet run in one child process, which is killed and reaped on
+/// drop, mirroring the testcontainers pattern of pairing services on a shared
+/// lifecycle.
+///
+/// Caveat: this child-process harness shells out to an `aptos` binary on `PATH`
+/// and binds the fixed ports 8080/8081, so only one instance can run at a time
+/// and tests using it are not parallel-safe. Run them single-threaded (e.g.
+/// `--test-threads=1`) or behind a shared lock.
+pub struct LocalTestnet {
+    node: Child,
+    rest_url: Url,
+    faucet_url: Url,
+}
+
+impl LocalTestnet {
+    /// Start a local testnet with an embedded faucet and block until its
+    /// ledger-info endpoint responds.
+    pub async fn new() -> anyhow::Result<Self> {
+        let node = Command::new("aptos")
+            .args([
+                "node",
+                "run-local-testnet",
+                "--with-faucet",
+                "--force-restart",
+                "--assume-yes",
+            ])
+            .spawn()?;
+
+        let rest_url = Url::parse(&format!("http://127.0.0.1:{}", DEFAULT_REST_PORT))?;
+        let faucet_url = Url::parse(&format!("http://127.0.0.1:{}", DEFAULT_FAUCET_PORT))?;
+
+        let testnet = LocalTestnet {
+            node,
+            rest_url,
+            faucet_url,
+        };
+        testnet.wait_for_readiness().await?;
+        Ok(testnet)
+    }
+
+    /// [`RestOptions`] pointing at this instance's REST endpoint.
+    pub fn rest_options(&self) -> RestOptions {
+        RestOptions::new(Some(self.rest_url.clone()), None)
+    }
+
+    /// [`FaucetOptions`] pointing at this instance's faucet endpoint.
+    pub fn faucet_options(&self) -> FaucetOptions {
+        FaucetOptions::new(Some(self.faucet_url.clone()))
+    }
+
+    async fn wait_for_readiness(&self) -> anyhow::Result<()> {
+        // Both the node and the faucet must be up before `FundAccount`-style
+        // tests run, otherwise they race a faucet that hasn't bound its port.
+        let info_url = self.rest_url.join("v1")?;
+        let faucet_health_url = self.faucet_url.join("health")?;
+        let client = reqwest::Client::new();
+        let deadline = Instant::now() + READINESS_TIMEOUT;
+        loop {
+            if is_ready(&client, info_url.clone()).await
+                && is_ready(&client, faucet_health_url.clone()).await
+            {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                anyhow::bail!(
+                    "Local testnet did not become ready within {}s",
+                    READINESS_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+/// Return true when `url` responds with a success status.
+async fn is_ready(client: &reqwest::Client, url: Url) -> bool {
+    matches!(client.get(url).send().await, Ok(response) if response.status().is_success())
+}
+
+impl Drop for LocalTestnet {
+    fn drop(&mut self) {
+        let _ = self.node.kill();
+        let _ = self.node.wait();
+    }
+}